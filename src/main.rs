@@ -1,10 +1,14 @@
+use std::collections::HashMap;
+use std::fmt;
 use std::io::stdin;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use clap::{Parser, Subcommand};
 use console::style;
+use rayon::prelude::*;
 
 use wordle_cli::lang::locale::replace_unicode;
 use wordle_cli::dictionary::{Dictionary, get_dictionary};
-use wordle_cli::maintenance::import::do_import;
+use wordle_cli::maintenance::import::{do_import, do_import_url};
 
 /// Play wordle, a word guessing game!
 #[derive(Parser)]
@@ -13,20 +17,48 @@ struct Arguments {
     #[clap(short, long)]
     language: Option<String>,
 
+    /// Word length to play with. Defaults to 5.
+    #[clap(long)]
+    length: Option<usize>,
+
+    /// Number of attempts before the game is lost. Defaults to 6.
+    #[clap(long)]
+    attempts: Option<usize>,
+
+    /// Print a spoiler-free emoji result grid after the game.
+    #[clap(long)]
+    share: bool,
+
     #[clap(subcommand)]
     command: Option<Commands>
 }
 
+/// Accepted word-length range, mirroring the bounds of classic variants.
+const MIN_LENGTH: usize = 3;
+const MAX_LENGTH: usize = 8;
+
+/// Accepted range for the number of attempts.
+const MIN_ATTEMPTS: usize = 1;
+const MAX_ATTEMPTS: usize = 10;
+
 #[derive(Subcommand)]
 enum Commands {
     /// Extend the dictionary
     Import {
         #[clap(short, long)]
-        /// File to import. Requires entries to be separated by newlines.
+        /// File or http(s) URL to import. Requires entries to be separated by newlines.
         source_file: String,
         /// Language of the dictionary to import.
         #[clap(short, long)]
         import_language: String
+    },
+    /// Assist solving a puzzle by suggesting guesses from the live candidate set
+    Solve,
+    /// Benchmark the solver against the dictionary and report win-rate statistics
+    Bench {
+        /// Only play the first N words instead of the whole dictionary.
+        #[clap(short, long)]
+        sample: Option<usize>
     }
 }
 
@@ -40,49 +72,72 @@ fn main() -> std::io::Result<()> {
 
     let lang = lang.as_str();
 
+    let length = args.length.unwrap_or(5);
+    let attempts = args.attempts.unwrap_or(6);
+
+    if !(MIN_LENGTH..=MAX_LENGTH).contains(&length) {
+        eprintln!("Invalid word length: must be between {} and {}.", MIN_LENGTH, MAX_LENGTH);
+        return Ok(());
+    }
+
+    if !(MIN_ATTEMPTS..=MAX_ATTEMPTS).contains(&attempts) {
+        eprintln!("Invalid attempt count: must be between {} and {}.", MIN_ATTEMPTS, MAX_ATTEMPTS);
+        return Ok(());
+    }
+
     match args.command {
         Some(command) => {
             match command {
                 Commands::Import { source_file, import_language } => {
-                    do_import(source_file, import_language.as_str())?;
+                    if source_file.starts_with("http://") || source_file.starts_with("https://") {
+                        do_import_url(&source_file, import_language.as_str())?;
+                    } else {
+                        do_import(source_file, import_language.as_str())?;
+                    }
+                }
+                Commands::Solve => {
+                    run_solver(lang, length);
+                }
+                Commands::Bench { sample } => {
+                    run_bench(lang, length, attempts, sample);
                 }
             }
         }
         _ => {
-            start_game(lang);
+            start_game(lang, length, attempts, args.share);
         }
     }
 
     Ok(())
 }
 
-fn start_game(lang: &str) {
-    print_welcome();
+fn start_game(lang: &str, word_len: usize, max_attempts: usize, share: bool) {
+    print_welcome(word_len, max_attempts);
 
     let dictionary = get_dictionary(lang);
-    let solution_option = dictionary.get_random_word();
+    let solution_option = dictionary.get_random_word(word_len);
+
+    let mut grid: Vec<Vec<Status>> = Vec::new();
 
     match solution_option {
         None => println!("Maybe the dictionary is empty?"),
         Some(solution) => {
 
             if solution.guessed {
-                check_word(&solution.word, &solution.word);
+                check_word(&solution.word, &solution.word, &mut grid);
 
                 println!("You won! Come back tomorrow!");
             } else {
-                let max_attempts = 6;
-
                 let mut full_match: bool = false;
 
                 let mut counter = 0;
                 while counter < max_attempts {
-                    let attempt: String = read_input(5, lang);
+                    let attempt: String = read_input(word_len, lang);
 
                     match dictionary.find_word(&attempt) {
                         Some(_) => {
-                            let guesses: i32 = max_attempts - counter - 1;
-                            full_match = check_word(&solution.word, &attempt);
+                            let guesses = max_attempts - counter - 1;
+                            full_match = check_word(&solution.word, &attempt, &mut grid);
 
                             if full_match == true {
                                 break;
@@ -98,7 +153,14 @@ fn start_game(lang: &str) {
 
                             counter += 1;
                         },
-                        None => println!("The guessed word is not in the word list.")
+                        None => {
+                            println!("The guessed word is not in the word list.");
+
+                            let suggestions = dictionary.suggest_closest(&attempt);
+                            if !suggestions.is_empty() {
+                                println!("Did you mean: {}?", suggestions.join(", "));
+                            }
+                        }
                     }
                 }
 
@@ -106,6 +168,10 @@ fn start_game(lang: &str) {
                     println!("Congratulations! You won!");
                     dictionary.guessed_word(solution);
                 }
+
+                if share {
+                    print!("{}", ResultGrid { attempts: grid, max_attempts });
+                }
             }
         }
     }
@@ -136,36 +202,303 @@ fn validate_user_input(user_input: &str, expected_len: usize) -> bool {
     user_input.len() == expected_len
 }
 
-fn check_word(solution_word: &str, guessed_word: &str) -> bool {
-    let guessed_characters: Vec<char> = guessed_word.chars().collect();
-    let solution_characters: Vec<char> = solution_word.chars().collect();
+/// Outcome of comparing a single guessed letter against the solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    /// Right letter in the right position.
+    Correct,
+    /// Right letter, but in a different position.
+    Present,
+    /// Letter not contained in the (remaining) solution.
+    Absent,
+}
+
+/// Evaluate a guess against the solution with the standard two-pass Wordle
+/// algorithm. The first pass pins exact matches and records every unmatched
+/// solution letter in a multiset; the second pass only marks a letter present
+/// while that multiset still has one to spend, so repeated letters no longer
+/// produce too many yellow hints.
+fn evaluate(solution_word: &str, guessed_word: &str) -> Vec<(char, Status)> {
+    let guessed: Vec<char> = guessed_word.chars().collect();
+    let solution: Vec<char> = solution_word.chars().collect();
+
+    let mut statuses: Vec<Status> = vec![Status::Absent; guessed.len()];
+    let mut remaining: HashMap<char, usize> = HashMap::new();
+
+    for i in 0..guessed.len() {
+        if i < solution.len() {
+            if guessed[i] == solution[i] {
+                statuses[i] = Status::Correct;
+            } else {
+                *remaining.entry(solution[i]).or_insert(0) += 1;
+            }
+        }
+    }
 
-    for i in 0..guessed_word.len() {
-        let index: Option<usize> = solution_word.find(guessed_characters[i]);
+    for i in 0..guessed.len() {
+        if statuses[i] == Status::Correct {
+            continue;
+        }
 
-        match index {
-            Some(_index) => {
-                if solution_characters[i] == guessed_characters[i] {
-                    print!("{} ", style(guessed_characters[i].to_string()).green())
-                } else {
-                    print!("{} ", style(guessed_characters[i].to_string()).yellow())
-                }
+        if let Some(count) = remaining.get_mut(&guessed[i]) {
+            if *count > 0 {
+                statuses[i] = Status::Present;
+                *count -= 1;
             }
-            None => { print!("{} ", guessed_characters[i]) }
+        }
+    }
+
+    guessed.into_iter().zip(statuses).collect()
+}
+
+fn check_word(solution_word: &str, guessed_word: &str, grid: &mut Vec<Vec<Status>>) -> bool {
+    let evaluation = evaluate(solution_word, guessed_word);
+
+    for (character, status) in &evaluation {
+        match status {
+            Status::Correct => print!("{} ", style(character.to_string()).green()),
+            Status::Present => print!("{} ", style(character.to_string()).yellow()),
+            Status::Absent => print!("{} ", character),
         }
     }
 
     println!();
 
+    // remember the row so it can be rendered into the shareable grid later
+    grid.push(evaluation.iter().map(|(_, status)| *status).collect());
+
     // check for full match
-    if String::from(solution_word).eq(guessed_word) {
-        return true;
+    String::from(solution_word).eq(guessed_word)
+}
+
+/// The spoiler-free emoji grid players paste into chats: a `n/N` header line
+/// (`X/N` when the last row is not solved) followed by one row of coloured
+/// squares per attempt.
+struct ResultGrid {
+    attempts: Vec<Vec<Status>>,
+    max_attempts: usize,
+}
+
+impl fmt::Display for ResultGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let solved = self
+            .attempts
+            .last()
+            .map(|row| row.iter().all(|status| *status == Status::Correct))
+            .unwrap_or(false);
+
+        if solved {
+            writeln!(f, "{}/{}", self.attempts.len(), self.max_attempts)?;
+        } else {
+            writeln!(f, "X/{}", self.max_attempts)?;
+        }
+
+        for row in &self.attempts {
+            for status in row {
+                let square = match status {
+                    Status::Correct => '🟩',
+                    Status::Present => '🟨',
+                    Status::Absent => '⬛',
+                };
+                write!(f, "{}", square)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Translate a feedback string (`g` green, `y` yellow, `b` gray) into the
+/// matching `Status` pattern. Returns `None` if any character is unexpected.
+fn parse_pattern(input: &str) -> Option<Vec<Status>> {
+    input
+        .chars()
+        .map(|c| match c {
+            'g' => Some(Status::Correct),
+            'y' => Some(Status::Present),
+            'b' => Some(Status::Absent),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Keep only the candidates that would have produced exactly `pattern` had they
+/// been the solution for `guess`. Reusing `evaluate` means the duplicate-letter
+/// rules (green pins a position, yellow forbids the position but requires the
+/// letter elsewhere, gray forbids the letter beyond its confirmed count) come
+/// for free.
+fn filter_candidates(candidates: Vec<String>, guess: &str, pattern: &[Status]) -> Vec<String> {
+    candidates
+        .into_iter()
+        .filter(|candidate| {
+            evaluate(candidate, guess)
+                .iter()
+                .map(|(_, status)| *status)
+                .eq(pattern.iter().copied())
+        })
+        .collect()
+}
+
+/// Rank the candidates by summed positional letter frequency across the live
+/// set and return the strongest suggestion.
+fn rank_candidates(candidates: &[String]) -> Option<&String> {
+    let mut frequencies: Vec<HashMap<char, usize>> = Vec::new();
+
+    for word in candidates {
+        for (i, c) in word.chars().enumerate() {
+            if i >= frequencies.len() {
+                frequencies.push(HashMap::new());
+            }
+
+            *frequencies[i].entry(c).or_insert(0) += 1;
+        }
     }
 
-    false
+    candidates.iter().max_by_key(|word| {
+        word.chars()
+            .enumerate()
+            .map(|(i, c)| frequencies[i].get(&c).copied().unwrap_or(0))
+            .sum::<usize>()
+    })
 }
 
-fn print_welcome() {
+fn run_solver(lang: &str, word_len: usize) {
+    let dictionary = get_dictionary(lang);
+    let mut candidates: Vec<String> = dictionary
+        .all_words()
+        .into_iter()
+        .filter(|word| word.chars().count() == word_len)
+        .collect();
+
+    if candidates.is_empty() {
+        println!("Maybe the dictionary is empty?");
+        return;
+    }
+
+    loop {
+        let suggestion = match rank_candidates(&candidates) {
+            Some(word) => word.clone(),
+            None => {
+                println!("No candidates left - double-check the feedback you entered.");
+                return;
+            }
+        };
+
+        println!(
+            "Suggested guess: {} ({} candidate(s) remaining)",
+            style(&suggestion).green(),
+            candidates.len()
+        );
+        println!("Enter the feedback (g = green, y = yellow, b = gray):");
+
+        let mut input = String::new();
+        stdin().read_line(&mut input).unwrap();
+
+        let pattern = match parse_pattern(input.trim()) {
+            Some(pattern) if pattern.len() == suggestion.len() => pattern,
+            _ => {
+                println!(
+                    "Please enter exactly {} feedback characters using only g, y and b.",
+                    suggestion.len()
+                );
+                continue;
+            }
+        };
+
+        if pattern.iter().all(|status| *status == Status::Correct) {
+            println!("Solved it: {}", style(&suggestion).green());
+            return;
+        }
+
+        candidates = filter_candidates(candidates, &suggestion, &pattern);
+    }
+}
+
+/// Play a single headless solver game against `solution`, returning the number
+/// of guesses needed to win or `None` if the solver ran out of attempts.
+fn solve_word(candidates: &[String], solution: &str, max_attempts: usize) -> Option<usize> {
+    let mut live: Vec<String> = candidates.to_vec();
+
+    for attempt in 1..=max_attempts {
+        let guess = rank_candidates(&live)?.clone();
+
+        let pattern: Vec<Status> = evaluate(solution, &guess)
+            .into_iter()
+            .map(|(_, status)| status)
+            .collect();
+
+        if pattern.iter().all(|status| *status == Status::Correct) {
+            return Some(attempt);
+        }
+
+        live = filter_candidates(live, &guess, &pattern);
+    }
+
+    None
+}
+
+fn run_bench(lang: &str, word_len: usize, max_attempts: usize, sample: Option<usize>) {
+    let dictionary = get_dictionary(lang);
+    let candidates: Vec<String> = dictionary
+        .all_words()
+        .into_iter()
+        .filter(|word| word.chars().count() == word_len)
+        .collect();
+
+    if candidates.is_empty() {
+        println!("Maybe the dictionary is empty?");
+        return;
+    }
+
+    let solutions: Vec<String> = match sample {
+        Some(n) => candidates.iter().take(n).cloned().collect(),
+        None => candidates.clone(),
+    };
+
+    let total = solutions.len();
+    let completed = AtomicUsize::new(0);
+
+    let results: Vec<Option<usize>> = solutions
+        .par_iter()
+        .map(|solution| {
+            let result = solve_word(&candidates, solution, max_attempts);
+            let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+            eprint!("\rBenchmarking... {}/{}", done, total);
+            result
+        })
+        .collect();
+
+    eprintln!();
+
+    let wins = results.iter().filter(|result| result.is_some()).count();
+    let total_guesses: usize = results.iter().flatten().sum();
+
+    // Index 0 collects the games that never found the word.
+    let mut distribution = vec![0usize; max_attempts + 1];
+    for result in &results {
+        distribution[result.unwrap_or(0)] += 1;
+    }
+
+    println!(
+        "Win rate: {:.1}% ({}/{})",
+        wins as f64 / total as f64 * 100.0,
+        wins,
+        total
+    );
+    if wins > 0 {
+        println!("Average guesses (wins): {:.2}", total_guesses as f64 / wins as f64);
+    }
+    println!("Distribution:");
+    for guesses in 1..=max_attempts {
+        println!("  {}: {}", guesses, distribution[guesses]);
+    }
+    println!("  fail: {}", distribution[0]);
+}
+
+fn print_welcome(word_len: usize, max_attempts: usize) {
+    let blanks = vec!["_"; word_len].join(" ");
+
     println!(r#"
 ____    __    ____  ______   .______       _______   __       _______          ______  __       __
 \   \  /  \  /   / /  __  \  |   _  \     |       \ |  |     |   ____|        /      ||  |     |  |
@@ -173,10 +506,10 @@ ____    __    ____  ______   .______       _______   __       _______          _
   \            /  |  |  |  | |      /     |  |  |  ||  |     |   __|  |______|  |     |  |     |  |
    \    /\    /   |  `--'  | |  |\  \----.|  '--'  ||  `----.|  |____        |  `----.|  `----.|  |
     \__/  \__/     \______/  | _| `._____||_______/ |_______||_______|        \______||_______||__|
-
-Welcome! Guess today's word in 6 guesses.
-_ _ _ _ _
-    "#)
+"#);
+    println!("Welcome! Guess today's word in {} guesses.", max_attempts);
+    println!("{}", blanks);
+    println!();
 }
 
 #[cfg(test)]
@@ -213,4 +546,65 @@ fn test_validate_user_input() {
     assert!(validate_user_input(
         replace_unicode("wölfe", "en").as_str(), 5
     ));
+}
+
+#[cfg(test)]
+#[test]
+fn test_evaluate_handles_repeated_letters() {
+    // Guessing "eerie" against "there" must not mark every E yellow.
+    assert_eq!(evaluate("there", "eerie"), vec![
+        ('e', Status::Present),
+        ('e', Status::Absent),
+        ('r', Status::Present),
+        ('i', Status::Absent),
+        ('e', Status::Correct),
+    ]);
+
+    assert_eq!(evaluate("abbey", "abbey"), vec![
+        ('a', Status::Correct),
+        ('b', Status::Correct),
+        ('b', Status::Correct),
+        ('e', Status::Correct),
+        ('y', Status::Correct),
+    ]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_filter_candidates_respects_duplicates() {
+    let candidates = vec![
+        String::from("there"),
+        String::from("steer"),
+        String::from("eerie"),
+        String::from("crane"),
+    ];
+
+    // Feedback for guessing "eerie" against the solution "there".
+    let pattern = vec![
+        Status::Present,
+        Status::Absent,
+        Status::Present,
+        Status::Absent,
+        Status::Correct,
+    ];
+
+    let filtered = filter_candidates(candidates, "eerie", &pattern);
+
+    assert!(filtered.contains(&String::from("there")));
+    assert!(!filtered.contains(&String::from("eerie")));
+    assert!(!filtered.contains(&String::from("crane")));
+}
+
+#[cfg(test)]
+#[test]
+fn test_result_grid_renders_emoji_rows() {
+    let grid = ResultGrid {
+        attempts: vec![
+            vec![Status::Absent, Status::Present, Status::Absent, Status::Absent, Status::Absent],
+            vec![Status::Correct, Status::Correct, Status::Correct, Status::Correct, Status::Correct],
+        ],
+        max_attempts: 6,
+    };
+
+    assert_eq!(grid.to_string(), "2/6\n⬛🟨⬛⬛⬛\n🟩🟩🟩🟩🟩\n");
 }
\ No newline at end of file